@@ -2,9 +2,13 @@ pub trait Heap<T: Ord> {
     type Handle;
 
     fn find_minimum(&self) -> Option<&T>;
+    /// Absorbs `heap_to_merge`'s elements into `self`. Mergeable-heap
+    /// designs are usually chosen to make this O(1), but implementations
+    /// are not guaranteed to hit that bound — check the implementing
+    /// type's own docs.
     fn merge(self, heap_to_merge: Self) -> Self;
     fn insert(&mut self, element: T) -> Self::Handle;
     fn extract_minimum(&mut self) -> Option<T>;
     fn decrease_key(&mut self, handle: &Self::Handle, new_element: T);
-    fn delete(&mut self, element: T);
+    fn delete(&mut self, handle: &Self::Handle);
 }
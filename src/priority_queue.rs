@@ -0,0 +1,152 @@
+use std::cmp::Ordering;
+
+use crate::heap::Heap;
+use crate::{FibonacciHeap, NodeID};
+
+/// An `(key, value)` pair ordered solely by `key`, so the underlying
+/// `FibonacciHeap` can carry an arbitrary payload alongside the priority
+/// without the caller having to pack both into a single `Ord` type.
+struct Entry<K, V> {
+    key: K,
+    value: V,
+}
+
+impl<K: Ord, V> PartialEq for Entry<K, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+    }
+}
+
+impl<K: Ord, V> Eq for Entry<K, V> {}
+
+impl<K: Ord, V> PartialOrd for Entry<K, V> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<K: Ord, V> Ord for Entry<K, V> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key.cmp(&other.key)
+    }
+}
+
+/// Opaque handle to a `(key, value)` entry previously inserted into a
+/// `PriorityQueue`, used to `decrease_key` or `delete` it later.
+pub struct Handle(NodeID);
+
+/// A Fibonacci-heap-backed priority queue that decouples the ordering key
+/// from the payload, e.g. the tentative distance of a vertex (key) from the
+/// vertex id (value) in Dijkstra's algorithm or Prim's MST. This avoids
+/// packing both into a single `Ord` type just to drive `FibonacciHeap`.
+pub struct PriorityQueue<K: Ord, V> {
+    heap: FibonacciHeap<Entry<K, V>>,
+}
+
+impl<K: Ord, V> Default for PriorityQueue<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Ord, V> PriorityQueue<K, V> {
+    pub fn new() -> PriorityQueue<K, V> {
+        PriorityQueue {
+            heap: FibonacciHeap::new(),
+        }
+    }
+
+    pub fn find_minimum(&self) -> Option<(&K, &V)> {
+        self.heap
+            .find_minimum()
+            .map(|entry| (&entry.key, &entry.value))
+    }
+
+    /// Absorbs `other`'s entries into `self`. Note this is not O(1): each
+    /// heap owns an independent node arena, so every entry coming from
+    /// `other` needs to be migrated into `self`'s arena (see
+    /// `FibonacciHeap::migrate_tree`), which costs O(other's size).
+    pub fn merge(self, other: PriorityQueue<K, V>) -> PriorityQueue<K, V> {
+        PriorityQueue {
+            heap: self.heap.merge(other.heap),
+        }
+    }
+
+    pub fn insert(&mut self, key: K, value: V) -> Handle {
+        Handle(self.heap.insert(Entry { key, value }))
+    }
+
+    pub fn extract_minimum(&mut self) -> Option<(K, V)> {
+        self.heap
+            .extract_minimum()
+            .map(|entry| (entry.key, entry.value))
+    }
+
+    /// Decreases the key of the entry referred to by `handle`, leaving its
+    /// value untouched. Delegates to `FibonacciHeap::decrease_key_by` so the
+    /// cut/mark/min-update logic lives in exactly one place, only supplying
+    /// how to compare/write the key so the payload never has to be moved
+    /// out and back in.
+    pub fn decrease_key(&mut self, handle: &Handle, new_key: K) {
+        self.heap.decrease_key_by(
+            handle.0 .0,
+            handle.0 .1,
+            new_key,
+            |new_key, current: &Entry<K, V>| new_key.cmp(&current.key) == Ordering::Greater,
+            |new_key, current: &mut Entry<K, V>| current.key = new_key,
+        );
+    }
+
+    pub fn delete(&mut self, handle: &Handle) {
+        self.heap.delete(&handle.0);
+    }
+
+    /// The potential Φ = t + 2m from the amortized analysis underlying the
+    /// O(1)/O(log n) bounds on the other operations: `t` is the number of
+    /// root trees and `m` the number of marked nodes.
+    pub fn potential(&self) -> usize {
+        self.heap.potential()
+    }
+
+    /// Invariant check: panics if the heap-order property, the cached
+    /// degrees, or the minimum pointer are ever violated. Intended for
+    /// callers to sprinkle through their own tests, not for production use.
+    pub fn assert_valid(&self) {
+        self.heap.assert_valid();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PriorityQueue;
+
+    #[test]
+    fn priority_queue_test() {
+        let mut pq: PriorityQueue<i32, &str> = PriorityQueue::new();
+        assert_eq!(pq.find_minimum(), None);
+        assert_eq!(pq.extract_minimum(), None);
+
+        pq.insert(5, "vertex_a");
+        let handle_b = pq.insert(10, "vertex_b");
+        assert_eq!(pq.find_minimum(), Some((&5, &"vertex_a")));
+
+        pq.decrease_key(&handle_b, 1);
+        assert_eq!(pq.find_minimum(), Some((&1, &"vertex_b")));
+
+        assert_eq!(pq.extract_minimum(), Some((1, "vertex_b")));
+        assert_eq!(pq.extract_minimum(), Some((5, "vertex_a")));
+        assert_eq!(pq.extract_minimum(), None);
+
+        let handle_c = pq.insert(3, "vertex_c");
+        pq.insert(4, "vertex_d");
+        pq.delete(&handle_c);
+        assert_eq!(pq.find_minimum(), Some((&4, &"vertex_d")));
+        pq.assert_valid();
+
+        let mut other: PriorityQueue<i32, &str> = PriorityQueue::new();
+        other.insert(2, "vertex_e");
+        let pq = pq.merge(other);
+        assert_eq!(pq.find_minimum(), Some((&2, &"vertex_e")));
+        pq.assert_valid();
+    }
+}